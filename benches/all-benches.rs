@@ -20,6 +20,8 @@ macro_rules! long {
     }
 }
 
+// `to_vec` now builds forward, so the `forward` group is the reverse-free
+// fast path; `to_vec_reversed` pays for an extra in-place array reverse.
 fn bench_to_vec_small(c: &mut Criterion) {
     let t = (1, 2);
     c.bench_function("forward-small", |b| b.iter(|| black_box(t).to_vec()));
@@ -30,9 +30,9 @@
 //! ```
 //! # extern crate tuple_conv;
 //! # use tuple_conv::RepeatedTuple;
-//! fn do_something_2d<T, S>(a: T) where
-//!     T: RepeatedTuple<S>,
-//!     S: RepeatedTuple<i32>,
+//! fn do_something_2d<T, S, const N: usize, const M: usize>(a: T) where
+//!     T: RepeatedTuple<S, N>,
+//!     S: RepeatedTuple<i32, M>,
 //! { /* ... */ }
 //!
 //! do_something_2d(((1, 2, 3),
@@ -111,9 +111,11 @@
 //!
 //! ### Performance
 //!
-//! The details of the implementation are such that vectors are constructed in
-//! reverse, and `Vec<_>.reverse()` called, due to a limitation of Rust's macro
-//! system.
+//! [`to_array`] (and everything built on top of it - [`to_boxed_slice`] and
+//! [`to_vec`]) constructs its elements in the tuple's natural order, so the
+//! forward-facing conversions are allocation-free beyond whatever `Box`/`Vec`
+//! themselves need. The `_reversed` variants pay for that: they build the
+//! array forwards and then reverse it in place.
 //!
 //! This is not very significant (only ~10% increase with tuples of length 64),
 //! but something worth considering for performance-critical code. For more
@@ -127,8 +129,21 @@
 //! reduce them by using public functions simply as wrappers for your internals
 //! that only take vectors.
 //!
+//! # Serde
+//!
+//! With the `serde` feature enabled, [`serde_impl::AsSeq`] wraps any
+//! [`RepeatedTuple`]/[`TupleFromSlice`] so it can be serialized to, and
+//! deserialized from, a sequence - `(1, 2, 3)` becomes `[1, 2, 3]` and back.
+//!
 //! [`RepeatedTuple`]: trait.RepeatedTuple.html
 //! [`TupleOrVec`]: trait.TupleOrVec.html
+//! [`TupleFromSlice`]: trait.TupleFromSlice.html
+//! [`to_array`]: trait.RepeatedTuple.html#tymethod.to_array
+//! [`to_boxed_slice`]: trait.RepeatedTuple.html#method.to_boxed_slice
+//! [`to_vec`]: trait.RepeatedTuple.html#method.to_vec
+
+#[cfg(feature = "serde")]
+pub mod serde_impl;
 
 /// A trait implemented on all tuples composed of a single type.[^1]
 ///
@@ -138,6 +153,10 @@
 /// as a bound specifically for repeated tuples, though there's nothing
 /// stopping someone from implementing it on their own type.
 ///
+/// The `N` const parameter is simply the tuple's arity; it's what allows
+/// [`to_array`] to hand back a plain `[E; N]` instead of something
+/// heap-allocated.
+///
 /// A particularly nice use case of `RepeatedTuple` is ensuring a nice syntax
 /// for your API. Because this is already discussed in the
 /// [crate-level documentation], more examples will not be given here.
@@ -160,15 +179,32 @@
 ///
 /// [crate-level documentation]: index.html
 /// [`TupleOrVec`]: trait.TupleOrVec.html
-pub trait RepeatedTuple<E>: Sized {
+/// [`to_array`]: #tymethod.to_array
+pub trait RepeatedTuple<E, const N: usize>: Sized {
+    /// Converts a tuple directly into a stack-allocated array, with no heap
+    /// allocation involved
+    ///
+    /// This is the primitive that the conversion methods, [`map_to_vec`],
+    /// [`fold`] and [`into_iter`] are built from; [`iter`] and [`iter_mut`]
+    /// are implemented separately for each tuple.
+    ///
+    /// [`map_to_vec`]: #method.map_to_vec
+    /// [`fold`]: #method.fold
+    /// [`into_iter`]: #method.into_iter
+    /// [`iter`]: #tymethod.iter
+    /// [`iter_mut`]: #tymethod.iter_mut
+    fn to_array(self) -> [E; N];
+
     /// Converts a tuple to a boxed slice, with elements in reverse order
-    fn to_boxed_slice_reversed(self) -> Box<[E]>;
+    fn to_boxed_slice_reversed(self) -> Box<[E]> {
+        let mut arr = self.to_array();
+        arr.reverse();
+        arr.into()
+    }
 
     /// Converts a tuple to a boxed slice of its elements
     fn to_boxed_slice(self) -> Box<[E]> {
-        let mut s = self.to_boxed_slice_reversed();
-        s.reverse();
-        s
+        self.to_array().into()
     }
 
     /// Converts a tuple to a vector, with elements in reverse order
@@ -178,7 +214,35 @@ pub trait RepeatedTuple<E>: Sized {
 
     /// Converts a tuple to a vector of its elements
     fn to_vec(self) -> Vec<E> {
-        self.to_boxed_slice().into_vec()
+        self.to_array().into()
+    }
+
+    /// Applies a closure to each element of the tuple, collecting the results
+    /// into a vector
+    fn map_to_vec<U, F: FnMut(E) -> U>(self, f: F) -> Vec<U> {
+        self.to_array().map(f).into()
+    }
+
+    /// Folds the tuple's elements into a single accumulated value
+    ///
+    /// Elements are folded in the tuple's natural order, i.e. `self.0` is
+    /// folded in before `self.1`, and so on.
+    fn fold<A, F: FnMut(A, E) -> A>(self, init: A, f: F) -> A {
+        self.to_array().into_iter().fold(init, f)
+    }
+
+    /// Returns an iterator over references to the tuple's elements, with no
+    /// heap allocation involved
+    fn iter(&self) -> std::array::IntoIter<&E, N>;
+
+    /// Returns an iterator over mutable references to the tuple's elements,
+    /// with no heap allocation involved
+    fn iter_mut(&mut self) -> std::array::IntoIter<&mut E, N>;
+
+    /// Converts the tuple into an iterator over its elements, with no heap
+    /// allocation involved
+    fn into_iter(self) -> std::array::IntoIter<E, N> {
+        self.to_array().into_iter()
     }
 }
 
@@ -208,15 +272,102 @@ impl<E> TupleOrVec<E> for Vec<E> {
     }
 }
 
+/// A trait implemented on all tuples composed of a single type, providing the
+/// reverse direction of [`RepeatedTuple`]: building a tuple back up from a
+/// slice or vector.
+///
+/// [`from_slice`] only ever looks at the first `N` elements of the slice,
+/// ignoring anything past that - it's the length check that's allowed to
+/// fail, not the contents.
+///
+/// [`RepeatedTuple`]: trait.RepeatedTuple.html
+/// [`from_slice`]: #tymethod.from_slice
+pub trait TupleFromSlice<E: Clone, const N: usize>: Sized {
+    /// Builds a tuple from the first `N` elements of a slice, returning
+    /// `None` if the slice is shorter than `N`
+    fn from_slice(s: &[E]) -> Option<Self>;
+
+    /// Tries to build a tuple from a vector, handing the vector back if it
+    /// didn't hold at least `N` elements
+    fn try_from_vec(v: Vec<E>) -> Result<Self, Vec<E>> {
+        if v.len() < N {
+            return Err(v);
+        }
+
+        match Self::from_slice(&v) {
+            Some(t) => Ok(t),
+            None => Err(v),
+        }
+    }
+}
+
+// Builds a stack array in the tuple's natural (ascending) field order from a
+// descending list of field indices, without ever reversing anything at
+// runtime - the indices just get nested from the inside out as the macro
+// recurses, so the last one processed (the smallest) ends up first in the
+// output.
+macro_rules! fwd_array {
+    (@acc [$($acc:tt)*]; $self:ident;) => { [$($acc)*] };
+    (@acc [$($acc:tt)*]; $self:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_array!(@acc [$self.$head, $($acc)*]; $self; $($tail),*)
+    };
+    ($self:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_array!(@acc []; $self; $head $(, $tail)*)
+    };
+}
+
+// Same as `fwd_array`, but over `&self.$idx` references.
+macro_rules! fwd_ref_array {
+    (@acc [$($acc:tt)*]; $self:ident;) => { [$($acc)*] };
+    (@acc [$($acc:tt)*]; $self:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_ref_array!(@acc [&$self.$head, $($acc)*]; $self; $($tail),*)
+    };
+    ($self:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_ref_array!(@acc []; $self; $head $(, $tail)*)
+    };
+}
+
+// Same as `fwd_array`, but over `&mut self.$idx` references.
+macro_rules! fwd_mut_array {
+    (@acc [$($acc:tt)*]; $self:ident;) => { [$($acc)*] };
+    (@acc [$($acc:tt)*]; $self:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_mut_array!(@acc [&mut $self.$head, $($acc)*]; $self; $($tail),*)
+    };
+    ($self:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_mut_array!(@acc []; $self; $head $(, $tail)*)
+    };
+}
+
+// Same as `fwd_array`, but cloning each `s[$idx]` into a tuple instead of
+// indexing `self`.
+macro_rules! fwd_tuple_from_slice {
+    (@acc [$($acc:tt)*]; $s:ident;) => { ($($acc)*) };
+    (@acc [$($acc:tt)*]; $s:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_tuple_from_slice!(@acc [$s[$head].clone(), $($acc)*]; $s; $($tail),*)
+    };
+    ($s:ident; $head:tt $(, $tail:tt)*) => {
+        fwd_tuple_from_slice!(@acc []; $s; $head $(, $tail)*)
+    };
+}
+
 macro_rules! impl_tuple {
     (
         $E:ident,
         ($tup_head:ident, $($tup:ident),+),
-        $idx_head:tt @ $($idx:tt)@+
+        $idx_head:tt @ $($idx:tt)@+,
+        $n_head:tt @ $($n:tt)@+
     ) => {
-        impl<$E> RepeatedTuple<$E> for ($tup_head, $($tup),+) {
-            fn to_boxed_slice_reversed(self) -> Box<[$E]> {
-                Box::new([self.$idx_head, $(self.$idx),+])
+        impl<$E> RepeatedTuple<$E, $n_head> for ($tup_head, $($tup),+) {
+            fn to_array(self) -> [$E; $n_head] {
+                fwd_array!(self; $idx_head $(, $idx)+)
+            }
+
+            fn iter(&self) -> std::array::IntoIter<&$E, $n_head> {
+                IntoIterator::into_iter(fwd_ref_array!(self; $idx_head $(, $idx)+))
+            }
+
+            fn iter_mut(&mut self) -> std::array::IntoIter<&mut $E, $n_head> {
+                IntoIterator::into_iter(fwd_mut_array!(self; $idx_head $(, $idx)+))
             }
         }
 
@@ -226,10 +377,21 @@ macro_rules! impl_tuple {
             }
         }
 
+        impl<$E: Clone> TupleFromSlice<$E, $n_head> for ($tup_head, $($tup),+) {
+            fn from_slice(s: &[$E]) -> Option<Self> {
+                if s.len() < $n_head {
+                    return None;
+                }
+
+                Some(fwd_tuple_from_slice!(s; $idx_head $(, $idx)+))
+            }
+        }
+
         impl_tuple! {
             $E,
             ($($tup),+),
-            $($idx)@+
+            $($idx)@+,
+            $($n)@+
         }
     };
 
@@ -237,11 +399,20 @@ macro_rules! impl_tuple {
     (
         $E:ident,
         ($tup:ident),
-        $idx:tt
+        $idx:tt,
+        $n:tt
     ) => {
-        impl<$E> RepeatedTuple<$E> for ($tup,) {
-            fn to_boxed_slice_reversed(self) -> Box<[$E]> {
-                Box::new([self.$idx])
+        impl<$E> RepeatedTuple<$E, $n> for ($tup,) {
+            fn to_array(self) -> [$E; $n] {
+                [self.$idx]
+            }
+
+            fn iter(&self) -> std::array::IntoIter<&$E, $n> {
+                IntoIterator::into_iter([&self.$idx])
+            }
+
+            fn iter_mut(&mut self) -> std::array::IntoIter<&mut $E, $n> {
+                IntoIterator::into_iter([&mut self.$idx])
             }
         }
 
@@ -250,6 +421,16 @@ macro_rules! impl_tuple {
                 RepeatedTuple::to_vec(self)
             }
         }
+
+        impl<$E: Clone> TupleFromSlice<$E, $n> for ($tup,) {
+            fn from_slice(s: &[$E]) -> Option<Self> {
+                if s.len() < $n {
+                    return None;
+                }
+
+                Some((s[$idx].clone(),))
+            }
+        }
     }
 }
 
@@ -295,7 +476,27 @@ impl_tuple! {
     15 @ 14 @ 13 @ 12 @
     11 @ 10 @  9 @  8 @
      7 @  6 @  5 @  4 @
-     3 @  2 @  1 @  0
+     3 @  2 @  1 @  0,
+
+    64 @ 63 @ 62 @ 61 @
+    60 @ 59 @ 58 @ 57 @
+    56 @ 55 @ 54 @ 53 @
+    52 @ 51 @ 50 @ 49 @
+
+    48 @ 47 @ 46 @ 45 @
+    44 @ 43 @ 42 @ 41 @
+    40 @ 39 @ 38 @ 37 @
+    36 @ 35 @ 34 @ 33 @
+
+    32 @ 31 @ 30 @ 29 @
+    28 @ 27 @ 26 @ 25 @
+    24 @ 23 @ 22 @ 21 @
+    20 @ 19 @ 18 @ 17 @
+
+    16 @ 15 @ 14 @ 13 @
+    12 @ 11 @ 10 @  9 @
+     8 @  7 @  6 @  5 @
+     4 @  3 @  2 @  1
 }
 
 #[cfg(test)]
@@ -317,6 +518,19 @@ mod tests {
             )
         };
 
+        (slice) => {
+            [
+                1, 2, 3, 4, 5, 6, 7, 8,
+                9, 10, 11, 12, 13, 14, 15, 16,
+                17, 18, 19, 20, 21, 22, 23, 24,
+                25, 26, 27, 28, 29, 30, 31, 32,
+                33, 34, 35, 36, 37, 38, 39, 40,
+                41, 42, 43, 44, 45, 46, 47, 48,
+                49, 50, 51, 52, 53, 54, 55, 56,
+                57, 58, 59, 60, 61, 62, 63, 64,
+            ]
+        };
+
         (slice_reversed) => {
             [
                 64, 63, 62, 61, 60, 59, 58, 57,
@@ -329,6 +543,19 @@ mod tests {
                 8, 7, 6, 5, 4, 3, 2, 1,
             ]
         };
+
+        (ty) => {
+            (
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+                i32, i32, i32, i32, i32, i32, i32, i32,
+            )
+        };
     }
 
     #[test]
@@ -366,4 +593,119 @@ mod tests {
         let v = t.to_vec_reversed();
         assert_eq!(v, [3, 2, 1]);
     }
+
+    #[test]
+    fn to_array() {
+        let t = (1,);
+        assert_eq!(t.to_array(), [1]);
+
+        let t = (1, 2, 3);
+        assert_eq!(t.to_array(), [1, 2, 3]);
+
+        let t = long!(tuple);
+        assert_eq!(t.to_array(), long!(slice));
+    }
+
+    #[test]
+    fn map_to_vec() {
+        let t = (1, 2, 3);
+        let v = t.map_to_vec(|x| x.to_string());
+        assert_eq!(v, ["1", "2", "3"]);
+
+        let t = long!(tuple);
+        let v = t.map_to_vec(|x| x.to_string());
+        let expected: Vec<String> = long!(slice).iter().map(|x| x.to_string()).collect();
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn fold() {
+        let t = (1, 2, 3);
+        let sum = t.fold(0, |acc, x| acc + x);
+        assert_eq!(sum, 6);
+
+        let t = (1, 2, 3);
+        let joined = t.fold(String::new(), |mut acc, x| {
+            acc.push_str(&x.to_string());
+            acc
+        });
+        assert_eq!(joined, "123");
+
+        let t = long!(tuple);
+        let sum = t.fold(0, |acc, x| acc + x);
+        assert_eq!(sum, long!(slice).iter().sum::<i32>());
+    }
+
+    #[test]
+    fn iter() {
+        let t = (1, 2, 3);
+        let v: Vec<&i32> = t.iter().collect();
+        assert_eq!(v, [&1, &2, &3]);
+
+        let t = long!(tuple);
+        let v: Vec<&i32> = t.iter().collect();
+        let expected: Vec<&i32> = long!(slice).iter().collect();
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut t = (1, 2, 3);
+        for x in t.iter_mut() {
+            *x *= 10;
+        }
+        assert_eq!(t, (10, 20, 30));
+
+        let mut t = long!(tuple);
+        for x in t.iter_mut() {
+            *x *= 10;
+        }
+        let mut expected = long!(slice);
+        for x in expected.iter_mut() {
+            *x *= 10;
+        }
+        assert_eq!(t.to_array(), expected);
+    }
+
+    #[test]
+    fn into_iter() {
+        let t = (1, 2, 3);
+        let v: Vec<i32> = RepeatedTuple::into_iter(t).collect();
+        assert_eq!(v, [1, 2, 3]);
+
+        let t = long!(tuple);
+        let v: Vec<i32> = RepeatedTuple::into_iter(t).collect();
+        assert_eq!(v, long!(slice));
+    }
+
+    #[test]
+    fn from_slice() {
+        use crate::TupleFromSlice;
+
+        let t: Option<(i32, i32, i32)> = TupleFromSlice::from_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!(t, Some((1, 2, 3)));
+
+        let t: Option<(i32, i32, i32)> = TupleFromSlice::from_slice(&[1, 2]);
+        assert_eq!(t, None);
+
+        // std only implements `PartialEq`/`Debug` on tuples up to a fixed
+        // arity, so compare through `to_array` instead of the tuple itself.
+        let t: Option<long!(ty)> = TupleFromSlice::from_slice(&long!(slice));
+        assert_eq!(t.map(RepeatedTuple::to_array), Some(long!(slice)));
+    }
+
+    #[test]
+    fn try_from_vec() {
+        use crate::TupleFromSlice;
+
+        let t: Result<(i32, i32, i32), _> = TupleFromSlice::try_from_vec(vec![1, 2, 3, 4]);
+        assert_eq!(t, Ok((1, 2, 3)));
+
+        let v = vec![1, 2];
+        let t: Result<(i32, i32, i32), _> = TupleFromSlice::try_from_vec(v.clone());
+        assert_eq!(t, Err(v));
+
+        let t: Result<long!(ty), _> = TupleFromSlice::try_from_vec(long!(slice).to_vec());
+        assert_eq!(t.map(RepeatedTuple::to_array), Ok(long!(slice)));
+    }
 }
@@ -0,0 +1,101 @@
+//! Serde support for [`RepeatedTuple`], gated behind the `serde` feature.
+//!
+//! Since neither tuples nor `Serialize`/`Deserialize` belong to this crate,
+//! we can't implement those traits directly on tuples without running into
+//! the orphan rules. [`AsSeq`] is a thin wrapper that sidesteps that: it
+//! serializes as a plain sequence (`(1, 2, 3)` becomes `[1, 2, 3]`) and
+//! deserializes back into the original tuple.
+//!
+//! [`RepeatedTuple`]: crate::RepeatedTuple
+
+use crate::{RepeatedTuple, TupleFromSlice};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+
+/// Wraps a [`RepeatedTuple`] so it can be serialized as, and deserialized
+/// from, a sequence of its elements.
+///
+/// `E` and `N` are carried on the wrapper (rather than only appearing in a
+/// `where` clause) so that the compiler can see how they relate to `T` -
+/// without that, they'd be unconstrained type/const parameters on the
+/// `Serialize`/`Deserialize` impls below.
+///
+/// [`RepeatedTuple`]: crate::RepeatedTuple
+pub struct AsSeq<T, E, const N: usize>(pub T, PhantomData<E>);
+
+impl<T, E, const N: usize> AsSeq<T, E, N> {
+    /// Wraps a value for sequence-based (de)serialization
+    pub fn new(value: T) -> Self {
+        AsSeq(value, PhantomData)
+    }
+
+    /// Unwraps this back into the underlying value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, E, const N: usize> Serialize for AsSeq<T, E, N>
+where
+    T: RepeatedTuple<E, N>,
+    E: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(N))?;
+        for e in self.0.iter() {
+            seq.serialize_element(e)?;
+        }
+        seq.end()
+    }
+}
+
+struct ExpectedLen(usize);
+
+impl de::Expected for ExpectedLen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} elements", self.0)
+    }
+}
+
+impl<'de, T, E, const N: usize> Deserialize<'de> for AsSeq<T, E, N>
+where
+    T: TupleFromSlice<E, N>,
+    E: Deserialize<'de> + Clone,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<E>::deserialize(deserializer)?;
+        if elements.len() != N {
+            return Err(de::Error::invalid_length(elements.len(), &ExpectedLen(N)));
+        }
+
+        Ok(AsSeq::new(
+            T::from_slice(&elements).expect("length was just checked above"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsSeq;
+
+    #[test]
+    fn round_trip() {
+        let wrapped = AsSeq::new((1, 2, 3));
+        let json = serde_json::to_string(&wrapped).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let round_tripped: AsSeq<(i32, i32, i32), i32, 3> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.into_inner(), (1, 2, 3));
+    }
+
+    #[test]
+    fn wrong_length_is_rejected() {
+        let result: Result<AsSeq<(i32, i32, i32), i32, 3>, _> =
+            serde_json::from_str("[1,2,3,4]");
+        assert!(result.is_err());
+    }
+}